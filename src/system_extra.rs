@@ -1,10 +1,57 @@
 //! Extra system utilities.
 //!
-//! This modules contains an extension trait for the System trait which adds useful transformation
-//! functions.
+//! This module contains [`run_system`], a one-shot runner for driving a `System` outside of a
+//! dispatch; the [`SystemExtra`] extension trait, which adds gating combinators (`pausable`,
+//! `run_if`, `on_state`/`on_state_any`, `on_enter`/`on_exit`, `run_if_changed`) to any plain
+//! [`System`]; the [`StateStack`] resource and its driver that back the state-edge combinators;
+//! [`Tracked`], the change-detection wrapper `run_if_changed` watches; and the
+//! [`OutputSystem`]/[`InputSystem`]/[`ChainExt`]
+//! hierarchy, which is kept separate from `SystemExtra` because its `chain` combinator connects
+//! two different system shapes (a value producer to a value consumer) rather than wrapping one
+//! `System` in another.
 
-use ecs::prelude::{Read, System};
-use shred::{RunningTime, SystemData};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use ecs::prelude::{Read, System, Write};
+use shred::{Resources, RunningTime, SystemData};
+
+/// Run a single system once against `res`, reproducing what one dispatch does to it: `setup`,
+/// fetch, `run`. Useful for driving a system from a test or from non-frame-driven control flow.
+///
+/// # Examples
+///
+/// ```rust
+/// use amethyst::{ecs::{System, Write}, system_extra::run_system, prelude::*};
+///
+/// struct AddNumber(u32);
+///
+/// impl<'s> System<'s> for AddNumber {
+///     type SystemData = Write<'s, u32>;
+///
+///     fn run(&mut self, mut number: Self::SystemData) {
+///         *number += self.0;
+///     }
+/// }
+///
+/// let mut world = World::new();
+///
+/// // no dispatcher involved: `setup`, fetch and `run` all happen right here.
+/// run_system(&mut world.res, &mut AddNumber(2));
+/// assert_eq!(2, *world.read_resource::<u32>());
+///
+/// run_system(&mut world.res, &mut AddNumber(3));
+/// assert_eq!(2 + 3, *world.read_resource::<u32>());
+/// ```
+pub fn run_system<'a, S>(res: &'a mut Resources, system: &mut S)
+where
+    S: System<'a>,
+    S::SystemData: SystemData<'a>,
+{
+    system.setup(res);
+    let data = <S::SystemData as SystemData<'a>>::fetch(res);
+    system.run(data);
+}
 
 /// Extra functionality associated systems.
 pub trait SystemExtra {
@@ -66,6 +113,339 @@ pub trait SystemExtra {
     where
         Self: Sized,
         V: Send + Sync + Default + Eq;
+
+    /// Gate a system on an arbitrary predicate over a resource `D`.
+    ///
+    /// Where [`pausable`] only supports the "resource equals value" case, `run_if` fetches `D`
+    /// read-only and hands it to `predicate`, which decides, each dispatch, whether the inner
+    /// system runs. `pausable(value)` is simply `run_if(move |v: &V| *v == value)`, but the
+    /// predicate may instead look at a frame counter, compare against several fields of a larger
+    /// resource, or check a count that some resource keeps. `D` is always one plain resource:
+    /// component storages, entity counts and several separate resources are not fetched for the
+    /// predicate (see [`RunIf`]).
+    ///
+    /// [`pausable`]: #tymethod.pausable
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{System, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// struct AddNumber(u32);
+    ///
+    /// impl<'s> System<'s> for AddNumber {
+    ///     type SystemData = Write<'s, u32>;
+    ///
+    ///     fn run(&mut self, mut number: Self::SystemData) {
+    ///         *number += self.0;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(AddNumber(2).run_if(|enabled: &bool| *enabled), "add", &[])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// // the predicate reads the `bool` resource and gates the inner system on it.
+    /// *world.write_resource() = 0u32;
+    /// *world.write_resource() = false;
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(0, *world.read_resource::<u32>());
+    ///
+    /// *world.write_resource() = true;
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(2, *world.read_resource::<u32>());
+    /// ```
+    fn run_if<D, F>(self, predicate: F) -> RunIf<Self, D, F>
+    where
+        Self: Sized,
+        D: Send + Sync + Default + 'static,
+        F: FnMut(&D) -> bool;
+
+    /// Gate a system on `state` being the active top of the [`StateStack`] resource.
+    ///
+    /// Unlike [`pausable`], which compares a single flat value, this cooperates with
+    /// [`StateStack`]'s `Push`/`Pop`/`Replace` transitions so overlay states (a pause menu over
+    /// gameplay, a dialog over the map) can be expressed. Remember to schedule a
+    /// [`StateStackDriver`] before any gated system so the transitions queued last frame are
+    /// applied first.
+    ///
+    /// [`pausable`]: #tymethod.pausable
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{System, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// enum State {
+    ///     Gameplay,
+    ///     Menu,
+    /// }
+    ///
+    /// struct Tick;
+    ///
+    /// impl<'s> System<'s> for Tick {
+    ///     type SystemData = Write<'s, u32>;
+    ///
+    ///     fn run(&mut self, mut ticks: Self::SystemData) {
+    ///         *ticks += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(StateStackDriver::<State>::default(), "states", &[])
+    ///     .with(Tick.on_state(State::Gameplay), "tick", &["states"])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// // nothing on the stack yet, so the gated system does not run.
+    /// *world.write_resource() = 0u32;
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(0, *world.read_resource::<u32>());
+    ///
+    /// // once Gameplay is the top, the system runs every dispatch.
+    /// world.write_resource::<StateStack<State>>().push(State::Gameplay);
+    /// dispatcher.dispatch(&mut world.res);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(2, *world.read_resource::<u32>());
+    ///
+    /// // push a Menu overlay: Gameplay is no longer the top, so it stops running.
+    /// world.write_resource::<StateStack<State>>().push(State::Menu);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(2, *world.read_resource::<u32>());
+    /// ```
+    fn on_state<T>(self, state: T) -> OnState<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static;
+
+    /// Gate a system on `state` existing *anywhere* on the [`StateStack`], not only at the top.
+    ///
+    /// This is the overlay-friendly companion to [`on_state`](#tymethod.on_state): a gameplay
+    /// system tied with `on_state_any(State::Gameplay)` keeps running while a pause menu sits on
+    /// top of it, whereas `on_state` stops the moment the menu is pushed. It is the declarative
+    /// form of `run_if(|s: &StateStack<T>| s.contains(&state))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{System, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// enum State {
+    ///     Gameplay,
+    ///     Menu,
+    /// }
+    ///
+    /// struct Tick;
+    ///
+    /// impl<'s> System<'s> for Tick {
+    ///     type SystemData = Write<'s, u32>;
+    ///
+    ///     fn run(&mut self, mut ticks: Self::SystemData) {
+    ///         *ticks += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(StateStackDriver::<State>::default(), "states", &[])
+    ///     .with(Tick.on_state_any(State::Gameplay), "tick", &["states"])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// world.write_resource::<StateStack<State>>().push(State::Gameplay);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    ///
+    /// // a Menu overlay covers Gameplay, but it is still present, so the system keeps running.
+    /// world.write_resource::<StateStack<State>>().push(State::Menu);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(2, *world.read_resource::<u32>());
+    ///
+    /// // replacing the stack drops Gameplay entirely, so the system stops.
+    /// world.write_resource::<StateStack<State>>().replace(State::Menu);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(2, *world.read_resource::<u32>());
+    /// ```
+    fn on_state_any<T>(self, state: T) -> OnStateAny<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static;
+
+    /// Run a system exactly once, on the dispatch during which `state` becomes the top.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{System, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// enum State {
+    ///     Gameplay,
+    /// }
+    ///
+    /// struct Tick;
+    ///
+    /// impl<'s> System<'s> for Tick {
+    ///     type SystemData = Write<'s, u32>;
+    ///
+    ///     fn run(&mut self, mut ticks: Self::SystemData) {
+    ///         *ticks += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(StateStackDriver::<State>::default(), "states", &[])
+    ///     .with(Tick.on_enter(State::Gameplay), "tick", &["states"])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// // the entering edge fires exactly once, not on subsequent dispatches.
+    /// world.write_resource::<StateStack<State>>().push(State::Gameplay);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    /// ```
+    fn on_enter<T>(self, state: T) -> OnEnter<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static;
+
+    /// Run a system exactly once, on the dispatch during which `state` stops being the top.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{System, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq, Eq)]
+    /// enum State {
+    ///     Gameplay,
+    /// }
+    ///
+    /// struct Tick;
+    ///
+    /// impl<'s> System<'s> for Tick {
+    ///     type SystemData = Write<'s, u32>;
+    ///
+    ///     fn run(&mut self, mut ticks: Self::SystemData) {
+    ///         *ticks += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(StateStackDriver::<State>::default(), "states", &[])
+    ///     .with(Tick.on_exit(State::Gameplay), "tick", &["states"])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// // entering Gameplay is not an exit, so nothing happens yet.
+    /// world.write_resource::<StateStack<State>>().push(State::Gameplay);
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(0, *world.read_resource::<u32>());
+    ///
+    /// // popping it off fires the exit edge exactly once.
+    /// world.write_resource::<StateStack<State>>().pop();
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    /// ```
+    fn on_exit<T>(self, state: T) -> OnExit<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static;
+
+    /// Only run the inner system when the [`Tracked`] resource `R` was mutated since this wrapper
+    /// last ran.
+    ///
+    /// Each wrapper keeps its own last-seen tick, so several systems may watch the same `R`
+    /// independently without clearing a shared flag out from under each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{System, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[derive(Default)]
+    /// struct Config(u32);
+    ///
+    /// struct Rebuild;
+    ///
+    /// impl<'s> System<'s> for Rebuild {
+    ///     type SystemData = Write<'s, u32>;
+    ///
+    ///     fn run(&mut self, mut rebuilds: Self::SystemData) {
+    ///         *rebuilds += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(Rebuild.run_if_changed::<Config>(), "rebuild", &[])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// // a freshly inserted resource reads as changed, so the first dispatch runs.
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    ///
+    /// // nothing touched the config, so the body is skipped.
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(1, *world.read_resource::<u32>());
+    ///
+    /// // a mutable borrow bumps the counter, so the next dispatch runs again.
+    /// world.write_resource::<Tracked<Config>>().0 = 42;
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(2, *world.read_resource::<u32>());
+    /// ```
+    fn run_if_changed<R>(self) -> RunIfChanged<Self, R>
+    where
+        Self: Sized,
+        R: Send + Sync + Default + 'static;
 }
 
 impl<'s, S> SystemExtra for S
@@ -82,6 +462,76 @@ where
             value,
         }
     }
+
+    fn run_if<D, F>(self, predicate: F) -> RunIf<Self, D, F>
+    where
+        Self: Sized,
+        D: Send + Sync + Default + 'static,
+        F: FnMut(&D) -> bool,
+    {
+        RunIf {
+            system: self,
+            predicate,
+            marker: PhantomData,
+        }
+    }
+
+    fn on_state<T>(self, state: T) -> OnState<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static,
+    {
+        OnState {
+            system: self,
+            state,
+        }
+    }
+
+    fn on_state_any<T>(self, state: T) -> OnStateAny<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static,
+    {
+        OnStateAny {
+            system: self,
+            state,
+        }
+    }
+
+    fn on_enter<T>(self, state: T) -> OnEnter<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static,
+    {
+        OnEnter {
+            system: self,
+            state,
+        }
+    }
+
+    fn on_exit<T>(self, state: T) -> OnExit<Self, T>
+    where
+        Self: Sized,
+        T: Send + Sync + Clone + Eq + 'static,
+    {
+        OnExit {
+            system: self,
+            state,
+        }
+    }
+
+    fn run_if_changed<R>(self) -> RunIfChanged<Self, R>
+    where
+        Self: Sized,
+        R: Send + Sync + Default + 'static,
+    {
+        RunIfChanged {
+            system: self,
+            last_seen: 0,
+            skipped: false,
+            marker: PhantomData,
+        }
+    }
 }
 
 /// A system that is enabled when `U` has a specific value.
@@ -110,3 +560,516 @@ where
         self.system.running_time()
     }
 }
+
+/// A system that only runs when `predicate` returns `true` for resource `D`.
+///
+/// Like [`Pausable`], `D` names the bare resource type; `Read<'s, D>` is fetched fresh inside
+/// `run` for whichever lifetime the current dispatch uses, so `RunIf` implements `System<'s>` for
+/// every `'s`, not just the one in scope when `.run_if(...)` was called. The `fn() -> D` phantom
+/// keeps the wrapper `Send + Sync` regardless of `D`.
+///
+/// Unlike a full `SystemData`, `D` can only ever be one plain resource: this cannot gate on
+/// component storages or join several independent resources in a single call. If the predicate
+/// needs to look at more than one thing — several resources, or an entity-count threshold —
+/// combine them into your own resource first and gate on that.
+pub struct RunIf<S, D, F> {
+    system: S,
+    predicate: F,
+    marker: PhantomData<fn() -> D>,
+}
+
+impl<'s, S, D, F> System<'s> for RunIf<S, D, F>
+where
+    S: System<'s>,
+    S::SystemData: SystemData<'s>,
+    D: Send + Sync + Default + 'static,
+    F: FnMut(&D) -> bool,
+{
+    type SystemData = (Read<'s, D>, S::SystemData);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (check, rest) = data;
+
+        if !(self.predicate)(&*check) {
+            return;
+        }
+
+        self.system.run(rest);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        self.system.running_time()
+    }
+}
+
+/// A transition to apply to a [`StateStack`] between dispatches.
+pub enum Transition<T> {
+    /// Pause the previous top and make `T` the new active top.
+    Push(T),
+    /// Remove the top and resume whatever was beneath it.
+    Pop,
+    /// Clear the stack down to the single state `T`.
+    Replace(T),
+}
+
+/// A stack of states of type `T`, used as a resource to drive layered, overlay-capable gating.
+///
+/// Transitions are queued with [`push`], [`pop`] and [`replace`] and applied between dispatches
+/// by a [`StateStackDriver`]; the driver also diffs the top against the previous frame so the
+/// `on_enter`/`on_exit` combinators can fire exactly once on an edge.
+///
+/// [`push`]: #method.push
+/// [`pop`]: #method.pop
+/// [`replace`]: #method.replace
+pub struct StateStack<T> {
+    stack: Vec<T>,
+    pending: Vec<Transition<T>>,
+    entered: Option<T>,
+    exited: Option<T>,
+}
+
+impl<T> Default for StateStack<T> {
+    fn default() -> Self {
+        StateStack {
+            stack: Vec::new(),
+            pending: Vec::new(),
+            entered: None,
+            exited: None,
+        }
+    }
+}
+
+impl<T> StateStack<T>
+where
+    T: Clone + Eq,
+{
+    /// Create an empty stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a stack with `initial` already on top.
+    pub fn with(initial: T) -> Self {
+        StateStack {
+            stack: vec![initial],
+            pending: Vec::new(),
+            entered: None,
+            exited: None,
+        }
+    }
+
+    /// The active top of the stack, if any.
+    pub fn top(&self) -> Option<&T> {
+        self.stack.last()
+    }
+
+    /// Whether `state` exists anywhere on the stack, not only at the top.
+    pub fn contains(&self, state: &T) -> bool {
+        self.stack.contains(state)
+    }
+
+    /// Queue a [`Transition::Push`] to apply on the next driver dispatch.
+    pub fn push(&mut self, state: T) {
+        self.pending.push(Transition::Push(state));
+    }
+
+    /// Queue a [`Transition::Pop`] to apply on the next driver dispatch.
+    pub fn pop(&mut self) {
+        self.pending.push(Transition::Pop);
+    }
+
+    /// Queue a [`Transition::Replace`] to apply on the next driver dispatch.
+    pub fn replace(&mut self, state: T) {
+        self.pending.push(Transition::Replace(state));
+    }
+}
+
+/// Applies the [`StateStack`] transitions queued since the last dispatch and records the
+/// resulting top edge for `on_enter`/`on_exit`.
+///
+/// Schedule this before every gated system so they observe a settled stack.
+pub struct StateStackDriver<T> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for StateStackDriver<T> {
+    fn default() -> Self {
+        StateStackDriver {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'s, T> System<'s> for StateStackDriver<T>
+where
+    T: Send + Sync + Clone + Eq + 'static,
+{
+    type SystemData = Write<'s, StateStack<T>>;
+
+    fn run(&mut self, mut stack: Self::SystemData) {
+        let previous = stack.stack.last().cloned();
+
+        for transition in ::std::mem::take(&mut stack.pending) {
+            match transition {
+                Transition::Push(state) => stack.stack.push(state),
+                Transition::Pop => {
+                    stack.stack.pop();
+                }
+                Transition::Replace(state) => {
+                    stack.stack.clear();
+                    stack.stack.push(state);
+                }
+            }
+        }
+
+        let current = stack.stack.last().cloned();
+
+        if previous == current {
+            stack.entered = None;
+            stack.exited = None;
+        } else {
+            stack.exited = previous;
+            stack.entered = current;
+        }
+    }
+}
+
+/// A system that only runs while `state` is the active top of the [`StateStack`].
+pub struct OnState<S, T> {
+    system: S,
+    state: T,
+}
+
+impl<'s, S, T> System<'s> for OnState<S, T>
+where
+    S: System<'s>,
+    S::SystemData: SystemData<'s>,
+    T: Send + Sync + Clone + Eq + 'static,
+{
+    type SystemData = (Read<'s, StateStack<T>>, S::SystemData);
+
+    fn run(&mut self, (stack, rest): Self::SystemData) {
+        if stack.top() != Some(&self.state) {
+            return;
+        }
+
+        self.system.run(rest);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        self.system.running_time()
+    }
+}
+
+/// A system that only runs while `state` exists anywhere on the [`StateStack`].
+pub struct OnStateAny<S, T> {
+    system: S,
+    state: T,
+}
+
+impl<'s, S, T> System<'s> for OnStateAny<S, T>
+where
+    S: System<'s>,
+    S::SystemData: SystemData<'s>,
+    T: Send + Sync + Clone + Eq + 'static,
+{
+    type SystemData = (Read<'s, StateStack<T>>, S::SystemData);
+
+    fn run(&mut self, (stack, rest): Self::SystemData) {
+        if !stack.contains(&self.state) {
+            return;
+        }
+
+        self.system.run(rest);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        self.system.running_time()
+    }
+}
+
+/// A system that runs once on the dispatch during which `state` becomes the top.
+pub struct OnEnter<S, T> {
+    system: S,
+    state: T,
+}
+
+impl<'s, S, T> System<'s> for OnEnter<S, T>
+where
+    S: System<'s>,
+    S::SystemData: SystemData<'s>,
+    T: Send + Sync + Clone + Eq + 'static,
+{
+    type SystemData = (Read<'s, StateStack<T>>, S::SystemData);
+
+    fn run(&mut self, (stack, rest): Self::SystemData) {
+        if stack.entered.as_ref() != Some(&self.state) {
+            return;
+        }
+
+        self.system.run(rest);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        self.system.running_time()
+    }
+}
+
+/// A system that runs once on the dispatch during which `state` stops being the top.
+pub struct OnExit<S, T> {
+    system: S,
+    state: T,
+}
+
+impl<'s, S, T> System<'s> for OnExit<S, T>
+where
+    S: System<'s>,
+    S::SystemData: SystemData<'s>,
+    T: Send + Sync + Clone + Eq + 'static,
+{
+    type SystemData = (Read<'s, StateStack<T>>, S::SystemData);
+
+    fn run(&mut self, (stack, rest): Self::SystemData) {
+        if stack.exited.as_ref() != Some(&self.state) {
+            return;
+        }
+
+        self.system.run(rest);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        self.system.running_time()
+    }
+}
+
+/// A system whose `run` yields a value, for use with [`ChainExt::chain`].
+///
+/// The stock [`System`] trait discards the result of `run`; `OutputSystem` keeps it so the value
+/// can be threaded straight into a following [`InputSystem`] within the same dispatch, instead of
+/// shuttling it through a shared resource.
+pub trait OutputSystem<'s> {
+    /// The data this system reads and writes, fetched from the resources.
+    type SystemData: SystemData<'s>;
+    /// The value produced by a run and handed to the downstream system.
+    type Output;
+
+    /// Run the system, returning the value to pipe onwards.
+    fn run(&mut self, data: Self::SystemData) -> Self::Output;
+
+    /// Return the running time of the system, used for scheduling estimation.
+    fn running_time(&self) -> RunningTime {
+        RunningTime::Average
+    }
+}
+
+/// A system that receives an extra input value produced by a preceding [`OutputSystem`].
+pub trait InputSystem<'s> {
+    /// The data this system reads and writes, fetched from the resources.
+    type SystemData: SystemData<'s>;
+    /// The value fed in from the upstream system.
+    type Input;
+
+    /// Run the system against `input` and the fetched data.
+    fn run(&mut self, input: Self::Input, data: Self::SystemData);
+
+    /// Return the running time of the system, used for scheduling estimation.
+    fn running_time(&self) -> RunningTime {
+        RunningTime::Average
+    }
+}
+
+/// Chaining combinator for systems carrying the [`OutputSystem`] convention.
+///
+/// This complements [`SystemExtra`]: where those combinators gate a plain [`System`], `chain`
+/// connects a value-producing [`OutputSystem`] to an [`InputSystem`] that consumes it.
+pub trait ChainExt<'s>: OutputSystem<'s> {
+    /// Feed the output of `self` into `next`, producing a single [`System`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use amethyst::{
+    ///     ecs::{Read, Write},
+    ///     shred::DispatcherBuilder,
+    ///     prelude::*,
+    /// };
+    ///
+    /// // Produces a value instead of discarding it: did the input resource go positive?
+    /// struct DidChange;
+    ///
+    /// impl<'s> OutputSystem<'s> for DidChange {
+    ///     type SystemData = Read<'s, i32>;
+    ///     type Output = bool;
+    ///
+    ///     fn run(&mut self, value: Self::SystemData) -> bool {
+    ///         *value > 0
+    ///     }
+    /// }
+    ///
+    /// // Consumes the upstream value as an extra argument.
+    /// struct React;
+    ///
+    /// impl<'s> InputSystem<'s> for React {
+    ///     type SystemData = Write<'s, u32>;
+    ///     type Input = bool;
+    ///
+    ///     fn run(&mut self, changed: bool, mut reacted: Self::SystemData) {
+    ///         if changed {
+    ///             *reacted += 10;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut dispatcher = DispatcherBuilder::default()
+    ///     .with(DidChange.chain(React), "pipe", &[])
+    ///     .build();
+    ///
+    /// dispatcher.setup(&mut world.res);
+    ///
+    /// *world.write_resource() = 0i32;
+    /// *world.write_resource() = 0u32;
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(0, *world.read_resource::<u32>());
+    ///
+    /// *world.write_resource() = 1i32;
+    /// dispatcher.dispatch(&mut world.res);
+    /// assert_eq!(10, *world.read_resource::<u32>());
+    /// ```
+    fn chain<B>(self, next: B) -> ChainSystem<Self, B>
+    where
+        Self: Sized,
+        B: InputSystem<'s, Input = Self::Output>;
+}
+
+impl<'s, A> ChainExt<'s> for A
+where
+    A: OutputSystem<'s>,
+{
+    fn chain<B>(self, next: B) -> ChainSystem<Self, B>
+    where
+        Self: Sized,
+        B: InputSystem<'s, Input = Self::Output>,
+    {
+        ChainSystem {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+/// Runs `A`, then feeds its returned value into `B` as an extra argument.
+pub struct ChainSystem<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<'s, A, B> System<'s> for ChainSystem<A, B>
+where
+    A: OutputSystem<'s>,
+    B: InputSystem<'s, Input = A::Output>,
+{
+    type SystemData = (A::SystemData, B::SystemData);
+
+    fn run(&mut self, (first, second): Self::SystemData) {
+        let output = self.first.run(first);
+        self.second.run(output, second);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        use std::cmp::max;
+
+        match max(self.first.running_time() as u8, self.second.running_time() as u8) {
+            1 => RunningTime::VeryShort,
+            2 => RunningTime::Short,
+            3 => RunningTime::Average,
+            4 => RunningTime::Long,
+            _ => RunningTime::VeryLong,
+        }
+    }
+}
+
+/// A resource wrapper that counts its own mutations, bumping the count on every [`DerefMut`].
+///
+/// The count starts at `1` so a reader whose last-seen tick defaults to `0` sees a brand-new
+/// resource as changed on its first look. A change is anything that took `&mut Tracked<R>`: the
+/// counter cannot tell a real edit from a mutable borrow that wrote nothing.
+pub struct Tracked<R> {
+    inner: R,
+    modified: u64,
+}
+
+impl<R> Tracked<R> {
+    /// Wrap `inner`, marking it as modified once so first-time readers react to it.
+    pub fn new(inner: R) -> Self {
+        Tracked { inner, modified: 1 }
+    }
+
+    /// The current modification count.
+    pub fn modified(&self) -> u64 {
+        self.modified
+    }
+}
+
+impl<R> Default for Tracked<R>
+where
+    R: Default,
+{
+    fn default() -> Self {
+        Tracked::new(R::default())
+    }
+}
+
+impl<R> Deref for Tracked<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.inner
+    }
+}
+
+impl<R> DerefMut for Tracked<R> {
+    fn deref_mut(&mut self) -> &mut R {
+        self.modified += 1;
+        &mut self.inner
+    }
+}
+
+/// A system that only runs when the [`Tracked`] resource `R` changed since its last run.
+pub struct RunIfChanged<S, R> {
+    system: S,
+    last_seen: u64,
+    skipped: bool,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<'s, S, R> System<'s> for RunIfChanged<S, R>
+where
+    S: System<'s>,
+    S::SystemData: SystemData<'s>,
+    R: Send + Sync + Default + 'static,
+{
+    type SystemData = (Read<'s, Tracked<R>>, S::SystemData);
+
+    fn run(&mut self, (tracked, rest): Self::SystemData) {
+        let modified = tracked.modified();
+
+        if modified == self.last_seen {
+            self.skipped = true;
+            return;
+        }
+
+        self.last_seen = modified;
+        self.skipped = false;
+        self.system.run(rest);
+    }
+
+    fn running_time(&self) -> RunningTime {
+        if self.skipped {
+            RunningTime::VeryShort
+        } else {
+            self.system.running_time()
+        }
+    }
+}